@@ -1,21 +1,67 @@
 use clap::{Arg, ArgAction, Command};
-use futures::{stream, StreamExt};
+use cookie_store::CookieStore;
 use indicatif::{ProgressBar, ProgressStyle};
-use rand::{seq::SliceRandom, thread_rng};
+use rand::{seq::SliceRandom, thread_rng, Rng};
 use regex::Regex;
 use reqwest::{Client, Proxy};
+use reqwest_cookie_store::CookieStoreMutex;
 use serde::{Deserialize, Serialize};
-use std::{collections::{HashSet, VecDeque}, fs, time::Duration};
+use std::{collections::{HashMap, HashSet, VecDeque}, fs, time::{Duration, Instant}};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use tokio::{
     fs::File,
     io::{self, AsyncBufReadExt, BufReader},
-    sync::Semaphore,
+    sync::{Mutex, Semaphore},
     time::sleep,
 };
 use url::Url;
 use serde_json;
 use csv;
+use std::fmt;
+
+/// Crate-level error type for the analysis/loading path: distinguishes
+/// failure classes (I/O, CSV, JSON, an unrecognised extension) so the CLI
+/// can map them to distinct exit codes and library consumers can match on
+/// them instead of relying on stderr side effects.
+#[derive(Debug)]
+enum RustfuzzError {
+    Io(std::io::Error),
+    Csv(csv::Error),
+    Json(serde_json::Error),
+    UnsupportedFormat(String),
+}
+
+impl fmt::Display for RustfuzzError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RustfuzzError::Io(e) => write!(f, "I/O error: {e}"),
+            RustfuzzError::Csv(e) => write!(f, "CSV error: {e}"),
+            RustfuzzError::Json(e) => write!(f, "JSON error: {e}"),
+            RustfuzzError::UnsupportedFormat(path) => write!(f, "unsupported file type for analysis: {path}"),
+        }
+    }
+}
+
+impl std::error::Error for RustfuzzError {}
+
+impl From<std::io::Error> for RustfuzzError {
+    fn from(e: std::io::Error) -> Self {
+        RustfuzzError::Io(e)
+    }
+}
+
+impl From<csv::Error> for RustfuzzError {
+    fn from(e: csv::Error) -> Self {
+        RustfuzzError::Csv(e)
+    }
+}
+
+impl From<serde_json::Error> for RustfuzzError {
+    fn from(e: serde_json::Error) -> Self {
+        RustfuzzError::Json(e)
+    }
+}
 
 #[derive(Debug, Deserialize)]
 struct Config {
@@ -27,23 +73,286 @@ struct Config {
     headers: Option<Vec<(String, String)>>,
     cookies: Option<Vec<(String, String)>>,
     auth_token: Option<String>,
+    cookie_jar: Option<String>,
     proxy: Option<String>,
-    rate_limit: Option<u64>,
+    rate_limit: Option<String>,
+    auto_throttle: Option<bool>,
     export: Option<String>,
+    resume: Option<bool>,
     crawl: Option<bool>,
     mutate: Option<bool>,
     payloads: Option<String>,
     openapi: Option<String>,
     analyze: Option<String>,
+    report_format: Option<String>,
+    show_all: Option<bool>,
+    recursion_depth: Option<usize>,
+    filter_size: Option<String>,
+    filter_words: Option<String>,
+    filter_lines: Option<String>,
+    filter_regex: Option<String>,
+    match_size: Option<String>,
+    match_words: Option<String>,
+    match_lines: Option<String>,
+    match_regex: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct FuzzResult {
     url: String,
     word: String,
     status: u16,
     reflected: bool,
     error: Option<String>,
+    content_length: usize,
+    word_count: usize,
+    line_count: usize,
+    method: String,
+    response_time_ms: u64,
+}
+
+/// Size/word/line signature of a single response, used both for
+/// auto-calibration of soft-404 pages and for the explicit --filter-*/--match-* flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ResponseSignature {
+    content_length: usize,
+    word_count: usize,
+    line_count: usize,
+}
+
+impl ResponseSignature {
+    fn from_body(body: &str) -> Self {
+        ResponseSignature {
+            content_length: body.len(),
+            word_count: body.split_whitespace().count(),
+            line_count: body.lines().count(),
+        }
+    }
+}
+
+/// A (status, signature) pair recorded during auto-calibration against
+/// randomly generated, almost-certainly-nonexistent paths.
+#[derive(Debug, Clone)]
+struct CalibrationSignature {
+    status: u16,
+    signature: ResponseSignature,
+}
+
+/// How close a response's word/line count must be to a calibration
+/// signature to be considered "the same soft-404 page" despite dynamic
+/// byte-length noise (timestamps, CSRF tokens, etc.).
+const CALIBRATION_TOLERANCE: i64 = 2;
+
+/// Fires a handful of requests against random, non-existent paths before the
+/// main run, so soft-404 pages (status 200 with a styled "not found" body) can
+/// be recognized and filtered instead of flooding the results.
+async fn calibrate(
+    client: &Client,
+    url: &str,
+    headers: &Vec<(String, String)>,
+    auth_token: Option<&str>,
+) -> Vec<CalibrationSignature> {
+    const PROBES: usize = 4;
+    const EXTENSIONS: [&str; 5] = ["", ".php", ".html", ".json", ".bak"];
+    const CHARSET: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+    let mut rng = thread_rng();
+    let mut signatures = Vec::new();
+
+    for _ in 0..PROBES {
+        let len = rng.gen_range(6..16);
+        let random_word: String = (0..len)
+            .map(|_| *CHARSET.choose(&mut rng).unwrap() as char)
+            .collect();
+        let probe_word = format!("{}{}", random_word, EXTENSIONS.choose(&mut rng).unwrap());
+        let probe_url = format!("{}/{}", url.trim_end_matches('/'), probe_word);
+
+        if let Ok((status, body, _final_url)) =
+            fuzz_url_adv(client, &probe_url, &probe_word, headers, auth_token, "GET", None).await
+        {
+            signatures.push(CalibrationSignature {
+                status,
+                signature: ResponseSignature::from_body(&body),
+            });
+        }
+    }
+
+    signatures
+}
+
+/// True if `status`/`sig` looks like one of the soft-404 baselines recorded
+/// during calibration: same status code, and word or line count within
+/// `CALIBRATION_TOLERANCE` of the baseline (raw byte length is ignored since
+/// dynamic pages vary there even when the "shape" of the page is identical).
+fn matches_calibration(status: u16, sig: &ResponseSignature, baselines: &[CalibrationSignature]) -> bool {
+    baselines.iter().any(|c| {
+        c.status == status
+            && ((sig.word_count as i64 - c.signature.word_count as i64).abs() <= CALIBRATION_TOLERANCE
+                || (sig.line_count as i64 - c.signature.line_count as i64).abs() <= CALIBRATION_TOLERANCE)
+    })
+}
+
+/// Parses a comma-separated list of sizes (`--filter-size 0,42,1234`) into the
+/// integers used by the `--filter-*`/`--match-*` flags. Unparseable entries are skipped.
+fn parse_size_list(spec: &str) -> Vec<usize> {
+    spec.split(',')
+        .filter_map(|s| s.trim().parse::<usize>().ok())
+        .collect()
+}
+
+/// A single fuzzing job pulled from the shared scan queue. `depth` tracks how
+/// many directory levels deep this job is, so recursion can stop at
+/// `--recursion-depth`. `from_wordlist` marks the initial, top-level jobs
+/// built from the word list (`--wordlist` plus any `--mutate`/`--payloads`
+/// additions, blank entries dropped) as opposed to crawler/OpenAPI/recursive
+/// discoveries; `wordlist_index` is that job's position in that combined
+/// word list (`None` for everything else) and is what `--resume`
+/// actually checkpoints, since the worker pool completes jobs out of order
+/// and a plain "N done" count isn't a safe prefix to skip by position.
+#[derive(Debug, Clone)]
+struct ScanJob {
+    target: String,
+    word: String,
+    depth: usize,
+    method: String,
+    body: Option<serde_json::Value>,
+    from_wordlist: bool,
+    wordlist_index: Option<usize>,
+}
+
+/// Sidecar checkpoint written beside `--export <file>` as `<file>.partial`
+/// while a scan is in progress. `--resume` uses it (plus the accompanying
+/// `<file>.partial.jsonl` result journal) to skip wordlist entries already
+/// completed by an interrupted run, as long as the target and wordlist
+/// contents still match. `completed_indices` is the *set* of wordlist
+/// positions finished so far (not just a count), since concurrent workers
+/// finish jobs out of order and a count can't be resumed as a safe prefix.
+#[derive(Debug, Serialize, Deserialize)]
+struct Checkpoint {
+    target: String,
+    wordlist_hash: u64,
+    completed_indices: Vec<usize>,
+}
+
+fn checkpoint_path(export: &str) -> String {
+    format!("{export}.partial")
+}
+
+fn journal_path(export: &str) -> String {
+    format!("{export}.partial.jsonl")
+}
+
+fn hash_wordlist(words: &[String]) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    words.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn load_checkpoint(path: &str) -> Option<Checkpoint> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn save_checkpoint(path: &str, checkpoint: &Checkpoint) {
+    if let Ok(json) = serde_json::to_string(checkpoint) {
+        let _ = fs::write(path, json);
+    }
+}
+
+/// Parses a `--rate-limit` value into requests/second. Accepts a plain number
+/// ("20" => 20 req/s) or the legacy per-request millisecond form kept as an
+/// alias ("200ms" => one request every 200ms => 5 req/s).
+fn parse_rate_limit(spec: &str) -> f64 {
+    let spec = spec.trim();
+    if let Some(ms) = spec.strip_suffix("ms") {
+        match ms.trim().parse::<f64>() {
+            Ok(ms) if ms > 0.0 => 1000.0 / ms,
+            _ => 0.0,
+        }
+    } else {
+        spec.parse().unwrap_or(0.0)
+    }
+}
+
+/// The floor the auto-throttle is allowed to halve the effective rate down to,
+/// so a flaky target can't stall the scan to a crawl.
+const MIN_RATE_PER_SEC: f64 = 0.5;
+
+/// A global (not per-task) requests/second cap shared by every worker in the
+/// pool, implemented as a token bucket: a background task refills a
+/// `Semaphore` at `target_rate`, and each request `acquire`s (consumes) one
+/// permit before it is allowed to fire. When `auto_throttle` is enabled, a
+/// 429/503 response halves the effective rate and the background task ramps
+/// it back toward the target over time.
+struct RateLimiter {
+    semaphore: Arc<Semaphore>,
+    capacity: usize,
+    target_millihz: AtomicU64,
+    current_millihz: AtomicU64,
+    auto_throttle: bool,
+}
+
+impl RateLimiter {
+    fn new(rate_per_sec: f64, auto_throttle: bool) -> Arc<Self> {
+        let capacity = rate_per_sec.ceil().max(1.0) as usize;
+        let millihz = (rate_per_sec * 1000.0) as u64;
+        let limiter = Arc::new(RateLimiter {
+            semaphore: Arc::new(Semaphore::new(capacity)),
+            capacity,
+            target_millihz: AtomicU64::new(millihz),
+            current_millihz: AtomicU64::new(millihz),
+            auto_throttle,
+        });
+
+        let refill = limiter.clone();
+        tokio::spawn(async move {
+            let tick = Duration::from_millis(100);
+            let mut carry = 0.0f64;
+            loop {
+                sleep(tick).await;
+
+                let rate = refill.current_millihz.load(Ordering::Relaxed) as f64 / 1000.0;
+                carry += rate * tick.as_secs_f64();
+                let to_add = carry.floor() as usize;
+                if to_add > 0 {
+                    carry -= to_add as f64;
+                    let available = refill.semaphore.available_permits();
+                    if available < refill.capacity {
+                        refill.semaphore.add_permits(to_add.min(refill.capacity - available));
+                    }
+                }
+
+                if refill.auto_throttle {
+                    let target = refill.target_millihz.load(Ordering::Relaxed);
+                    let current = refill.current_millihz.load(Ordering::Relaxed);
+                    if current < target {
+                        // Ramp back up by 5% of the target per tick so a brief
+                        // 429 storm doesn't snap straight back to full speed.
+                        let step = (target / 20).max(1);
+                        refill.current_millihz.store((current + step).min(target), Ordering::Relaxed);
+                    }
+                }
+            }
+        });
+
+        limiter
+    }
+
+    async fn acquire(&self) {
+        self.semaphore.acquire().await.unwrap().forget();
+    }
+
+    /// Called with every response status; halves the effective rate on
+    /// 429/503 when auto-throttle is enabled.
+    fn observe(&self, status: u16) {
+        if !self.auto_throttle || (status != 429 && status != 503) {
+            return;
+        }
+        let min_millihz = (MIN_RATE_PER_SEC * 1000.0) as u64;
+        let current = self.current_millihz.load(Ordering::Relaxed);
+        self.current_millihz.store((current / 2).max(min_millihz), Ordering::Relaxed);
+    }
 }
 
 #[tokio::main]
@@ -127,9 +436,16 @@ async fn main() {
             Arg::new("cookie")
                 .long("cookie")
                 .value_name("COOKIE")
-                .help("Custom cookie(s) (key:value, can be used multiple times)")
+                .help("Custom cookie(s) (key:value, can be used multiple times); seeds the cookie jar")
                 .action(ArgAction::Append),
         )
+        .arg(
+            Arg::new("cookie_jar")
+                .long("cookie-jar")
+                .value_name("FILE")
+                .help("Load cookies from this file (Netscape or JSON format) and persist Set-Cookie updates back to it")
+                .action(ArgAction::Set),
+        )
         .arg(
             Arg::new("auth")
                 .long("auth-token")
@@ -147,10 +463,16 @@ async fn main() {
         .arg(
             Arg::new("rate_limit")
                 .long("rate-limit")
-                .value_name("MS")
-                .help("Rate limit in milliseconds between requests")
+                .value_name("RPS")
+                .help("Global requests/second cap shared across all threads (e.g. \"20\"; the old per-request millisecond form is still accepted as \"200ms\")")
                 .action(ArgAction::Set),
         )
+        .arg(
+            Arg::new("auto_throttle")
+                .long("auto-throttle")
+                .help("Halve the effective rate limit on 429/503 responses and slowly ramp it back up")
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             Arg::new("export")
                 .long("export")
@@ -158,6 +480,68 @@ async fn main() {
                 .help("Export results to file (json/csv)")
                 .action(ArgAction::Set),
         )
+        .arg(
+            Arg::new("resume")
+                .long("resume")
+                .help("Resume a previous scan from the <export>.partial checkpoint left by an interrupted run (requires --export)")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("filter_size")
+                .long("filter-size")
+                .value_name("SIZE,...")
+                .help("Hide responses with this content length (comma-separated)")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("filter_words")
+                .long("filter-words")
+                .value_name("COUNT,...")
+                .help("Hide responses with this word count (comma-separated)")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("filter_lines")
+                .long("filter-lines")
+                .value_name("COUNT,...")
+                .help("Hide responses with this line count (comma-separated)")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("filter_regex")
+                .long("filter-regex")
+                .value_name("REGEX")
+                .help("Hide responses whose body matches this regex")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("match_size")
+                .long("match-size")
+                .value_name("SIZE,...")
+                .help("Only show responses with this content length (comma-separated)")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("match_words")
+                .long("match-words")
+                .value_name("COUNT,...")
+                .help("Only show responses with this word count (comma-separated)")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("match_lines")
+                .long("match-lines")
+                .value_name("COUNT,...")
+                .help("Only show responses with this line count (comma-separated)")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("match_regex")
+                .long("match-regex")
+                .value_name("REGEX")
+                .help("Only show responses whose body matches this regex")
+                .action(ArgAction::Set),
+        )
         .arg(
             Arg::new("mutate")
                 .long("mutate")
@@ -188,7 +572,29 @@ async fn main() {
             Arg::new("analyze")
                 .long("analyze")
                 .value_name("FILE")
-                .help("Analyze and beautifully print results from an export file")
+                .help("Analyze and beautifully print results from a .json, .jsonl, or .csv export file")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("report_format")
+                .long("output-format")
+                .visible_alias("report")
+                .value_name("FORMAT")
+                .help("Report format for --analyze: console (default), markdown, or html")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("show_all")
+                .long("show-all")
+                .help("With --analyze, include results matching the inferred soft-404 baseline")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("recursion_depth")
+                .long("recursion-depth")
+                .value_name("N")
+                .help("Recurse into discovered directories up to N levels deep (0 = disabled)")
+                .default_value("0")
                 .action(ArgAction::Set),
         )
         .get_matches();
@@ -224,14 +630,28 @@ async fn main() {
             headers: matches.get_many::<String>("headers").map(|vals| vals.map(|kv| split_kv(kv)).collect()),
             cookies: matches.get_many::<String>("cookie").map(|vals| vals.map(|kv| split_kv(kv)).collect()),
             auth_token: matches.get_one::<String>("auth").cloned(),
+            cookie_jar: matches.get_one::<String>("cookie_jar").cloned(),
             proxy: matches.get_one::<String>("proxy").cloned(),
-            rate_limit: matches.get_one::<String>("rate_limit").and_then(|s| s.parse().ok()),
+            rate_limit: matches.get_one::<String>("rate_limit").cloned(),
+            auto_throttle: matches.get_flag("auto_throttle").then_some(true),
             export: matches.get_one::<String>("export").cloned(),
+            resume: matches.get_flag("resume").then_some(true),
             crawl: matches.get_flag("crawl").then_some(true),
             mutate: matches.get_flag("mutate").then_some(true),
             payloads: matches.get_one::<String>("payloads").cloned(),
             openapi: matches.get_one::<String>("openapi").cloned(),
             analyze: matches.get_one::<String>("analyze").cloned(),
+            report_format: matches.get_one::<String>("report_format").cloned(),
+            show_all: matches.get_flag("show_all").then_some(true),
+            recursion_depth: matches.get_one::<String>("recursion_depth").and_then(|s| s.parse().ok()),
+            filter_size: matches.get_one::<String>("filter_size").cloned(),
+            filter_words: matches.get_one::<String>("filter_words").cloned(),
+            filter_lines: matches.get_one::<String>("filter_lines").cloned(),
+            filter_regex: matches.get_one::<String>("filter_regex").cloned(),
+            match_size: matches.get_one::<String>("match_size").cloned(),
+            match_words: matches.get_one::<String>("match_words").cloned(),
+            match_lines: matches.get_one::<String>("match_lines").cloned(),
+            match_regex: matches.get_one::<String>("match_regex").cloned(),
         }
     };
 
@@ -248,6 +668,9 @@ async fn main() {
     if matches.get_flag("mutate") {
         config.mutate = Some(true);
     }
+    if matches.get_flag("resume") {
+        config.resume = Some(true);
+    }
     if let Some(payloads) = matches.get_one::<String>("payloads") {
         config.payloads = Some(payloads.clone());
     }
@@ -257,11 +680,19 @@ async fn main() {
     if let Some(openapi) = matches.get_one::<String>("openapi") {
         config.openapi = Some(openapi.clone());
     }
+    if let Some(report_format) = matches.get_one::<String>("report_format") {
+        config.report_format = Some(report_format.clone());
+    }
+    if matches.get_flag("show_all") {
+        config.show_all = Some(true);
+    }
 
     // Unified feature switches
     let crawl_enabled = config.crawl.unwrap_or(false);
     let mutate_enabled = config.mutate.unwrap_or(false);
     let analyze_path = config.analyze.clone();
+    let report_format = OutputFormat::parse(config.report_format.as_deref());
+    let show_all = config.show_all.unwrap_or(false);
     let payloads_path = config.payloads.clone();
 
     let url = config.url.as_deref().unwrap_or("");
@@ -275,9 +706,10 @@ async fn main() {
         .split(',')
         .filter_map(|code| code.parse::<u16>().ok())
         .collect();
-    let rate_limit = config.rate_limit.unwrap_or(0);
+    let rate_per_sec = config.rate_limit.as_deref().map(parse_rate_limit).unwrap_or(0.0);
+    let auto_throttle = config.auto_throttle.unwrap_or(false);
 
-    println!(":: Method           : GET");
+    println!(":: Method           : GET (plus whatever methods --openapi declares)");
     println!(":: URL              : {}", url);
     println!(":: Wordlist         : {}", wordlist);
     println!(":: Threads          : {}", threads);
@@ -286,6 +718,13 @@ async fn main() {
     if let Some(proxy) = &config.proxy {
         println!(":: Proxy            : {}", proxy);
     }
+    if rate_per_sec > 0.0 {
+        println!(
+            ":: Rate limit       : {:.2} req/s globally{}",
+            rate_per_sec,
+            if auto_throttle { " (auto-throttle on 429/503)" } else { "" }
+        );
+    }
     if let Some(export) = &config.export {
         println!(":: Export           : {}", export);
     }
@@ -309,43 +748,132 @@ async fn main() {
         words.append(&mut payloads);
     }
 
+    let headers = config.headers.clone().unwrap_or_default();
+    let cookies = config.cookies.clone().unwrap_or_default();
+    let auth_token = config.auth_token.clone();
+
+    // Persistent cookie jar: loaded from --cookie-jar (if given), seeded with
+    // any --cookie flags, and shared by both the crawler and the main fuzzing
+    // client so Set-Cookie responses (e.g. a login flow) are tracked and
+    // replayed automatically instead of being lost after the response that set them.
+    let mut initial_jar = config.cookie_jar.as_deref().map(load_cookie_jar).unwrap_or_default();
+    if !cookies.is_empty() {
+        if let Ok(seed_url) = Url::parse(&format!("{}/", url.trim_end_matches('/'))) {
+            for (k, v) in &cookies {
+                if let Ok(cookie) = cookie::Cookie::parse(format!("{k}={v}")) {
+                    let _ = initial_jar.insert_raw(&cookie, &seed_url);
+                }
+            }
+        }
+    }
+    let cookie_jar = Arc::new(CookieStoreMutex::new(initial_jar));
+
+    let mut client_builder = Client::builder()
+        .timeout(Duration::from_secs(timeout))
+        .cookie_provider(cookie_jar.clone());
+    if let Some(proxy) = &config.proxy {
+        client_builder = client_builder.proxy(Proxy::all(proxy).expect("Invalid proxy"));
+    }
+    let client = client_builder.build().unwrap();
+
     // Crawl mode: find additional endpoints (now smart)
     let mut discovered = HashSet::new();
     if crawl_enabled {
-        let found = crawl(
-            url,
-            4,        // depth
-            1000,     // max pages
-            config.cookies.as_ref().unwrap_or(&vec![]),
-            config.headers.as_ref().unwrap_or(&vec![]),
-        ).await;
+        let found = crawl(url, 4, 1000, &headers, client.clone()).await;
         for endpoint in &found {
             println!(":: Discovered endpoint: {}", endpoint);
         }
         discovered.extend(found);
     }
 
-    // OpenAPI parsing (stub)
+    // OpenAPI/Swagger parsing: walk every path/method the spec declares and
+    // turn each into a concrete request (path params substituted, body sampled).
     let openapi_path = config.openapi.clone();
-    if let Some(openapi_url) = openapi_path {
-        let api_endpoints = parse_openapi(&openapi_url).await;
-        for ep in &api_endpoints {
-            println!(":: OpenAPI endpoint: {}", ep);
+    let api_endpoints = if let Some(openapi_source) = openapi_path {
+        let endpoints = parse_openapi(&openapi_source).await;
+        for ep in &endpoints {
+            println!(":: OpenAPI endpoint: {} {}", ep.method, ep.url);
+        }
+        endpoints
+    } else {
+        Vec::new()
+    };
+
+    let recursion_depth = config.recursion_depth.unwrap_or(0);
+    words.retain(|w| !w.trim().is_empty());
+
+    // Resumable scans: beside --export, a <export>.partial checkpoint tracks
+    // the *set* of top-level wordlist indices that have completed (the only
+    // job set with a stable, deterministic order) and a <export>.partial.jsonl
+    // journal carries the matched results seen so far. --resume replays the
+    // journal into `results` and skips exactly the completed indices by
+    // membership — not by truncating a prefix — since the worker pool
+    // finishes jobs out of order and a plain "N done" count isn't a safe
+    // contiguous prefix to skip.
+    let export_path = config.export.clone();
+    let wordlist_hash = hash_wordlist(&words);
+    let mut initial_results: Vec<FuzzResult> = Vec::new();
+    let mut completed_indices: HashSet<usize> = HashSet::new();
+    let mut resumed = false;
+    if let Some(export) = &export_path {
+        if config.resume.unwrap_or(false) {
+            match load_checkpoint(&checkpoint_path(export)) {
+                Some(ckpt) if ckpt.target == url && ckpt.wordlist_hash == wordlist_hash => {
+                    completed_indices = ckpt.completed_indices.into_iter().collect();
+                    if let Ok(contents) = fs::read_to_string(journal_path(export)) {
+                        for line in contents.lines() {
+                            if let Ok(r) = serde_json::from_str::<FuzzResult>(line) {
+                                initial_results.push(r);
+                            }
+                        }
+                    }
+                    resumed = true;
+                    println!(
+                        ":: Resuming scan: skipping {} already-completed wordlist entries ({} carried-over results)",
+                        completed_indices.len(),
+                        initial_results.len()
+                    );
+                }
+                Some(_) => println!(":: --resume requested but the checkpoint's target/wordlist doesn't match this run; starting fresh"),
+                None => println!(":: --resume requested but no checkpoint was found; starting fresh"),
+            }
         }
-        discovered.extend(api_endpoints);
     }
+    let words = Arc::new(words);
 
-    // Combine discovered endpoints with words
-    let mut targets: Vec<String> = words
+    // Shared work queue: a dynamic queue (rather than a fixed Vec) lets
+    // directories discovered mid-scan push a fresh batch of jobs for the
+    // worker pool to drain, enabling recursive content discovery.
+    let base = url.trim_end_matches('/').to_string();
+    let mut queue: VecDeque<ScanJob> = words
         .iter()
-        .filter(|w| !w.trim().is_empty())
-        .map(|w| format!("{}/{}", url.trim_end_matches('/'), w))
+        .enumerate()
+        .filter(|(i, _)| !completed_indices.contains(i))
+        .map(|(i, w)| ScanJob {
+            target: format!("{}/{}", base, w),
+            word: w.clone(),
+            depth: 0,
+            method: "GET".to_string(),
+            body: None,
+            from_wordlist: true,
+            wordlist_index: Some(i),
+        })
         .collect();
     for ep in discovered {
-        targets.push(ep);
+        let word = ep.rsplit('/').next().unwrap_or("").to_string();
+        queue.push_back(ScanJob { target: ep, word, depth: recursion_depth, method: "GET".to_string(), body: None, from_wordlist: false, wordlist_index: None });
     }
+    for ep in api_endpoints {
+        let word = ep.url.rsplit('/').next().unwrap_or("").to_string();
+        queue.push_back(ScanJob { target: ep.url, word, depth: recursion_depth, method: ep.method, body: ep.body, from_wordlist: false, wordlist_index: None });
+    }
+    let in_flight = Arc::new(AtomicUsize::new(queue.len()));
+
+    let mut scanned_bases = HashSet::new();
+    scanned_bases.insert(base);
+    let scanned_bases = Arc::new(Mutex::new(scanned_bases));
 
-    let progress_bar = ProgressBar::new(targets.len() as u64);
+    let progress_bar = ProgressBar::new(queue.len() as u64);
     progress_bar.set_style(
         ProgressStyle::default_bar()
             .template("{spinner:.green} [{elapsed_precise}] [{wide_bar:.cyan/blue}] {pos}/{len} ({eta})")
@@ -353,88 +881,288 @@ async fn main() {
             .progress_chars("#>-"),
     );
 
-    let mut client_builder = Client::builder().timeout(Duration::from_secs(timeout));
-    if let Some(proxy) = &config.proxy {
-        client_builder = client_builder.proxy(Proxy::all(proxy).expect("Invalid proxy"));
+    // Explicit --filter-*/--match-* flags
+    let filter_size: Vec<usize> = config.filter_size.as_deref().map(parse_size_list).unwrap_or_default();
+    let filter_words: Vec<usize> = config.filter_words.as_deref().map(parse_size_list).unwrap_or_default();
+    let filter_lines: Vec<usize> = config.filter_lines.as_deref().map(parse_size_list).unwrap_or_default();
+    let filter_regex = config.filter_regex.as_deref().map(|p| Regex::new(p).expect("Invalid --filter-regex"));
+    let match_size: Vec<usize> = config.match_size.as_deref().map(parse_size_list).unwrap_or_default();
+    let match_words: Vec<usize> = config.match_words.as_deref().map(parse_size_list).unwrap_or_default();
+    let match_lines: Vec<usize> = config.match_lines.as_deref().map(parse_size_list).unwrap_or_default();
+    let match_regex = config.match_regex.as_deref().map(|p| Regex::new(p).expect("Invalid --match-regex"));
+
+    // Auto-calibrate against soft-404 pages before the main run
+    let calibration = if !url.is_empty() {
+        let baselines = calibrate(&client, url, &headers, auth_token.as_deref()).await;
+        if !baselines.is_empty() {
+            println!(
+                ":: Auto-calibration: derived {} soft-404 signature(s): {:?}",
+                baselines.len(),
+                baselines.iter().map(|c| (c.status, c.signature.word_count, c.signature.line_count)).collect::<Vec<_>>()
+            );
+        }
+        baselines
+    } else {
+        Vec::new()
+    };
+    if !filter_size.is_empty() || !filter_words.is_empty() || !filter_lines.is_empty() || filter_regex.is_some() {
+        println!(
+            ":: Active filters   : size={:?} words={:?} lines={:?} regex={:?}",
+            filter_size, filter_words, filter_lines, config.filter_regex
+        );
+    }
+    if !match_size.is_empty() || !match_words.is_empty() || !match_lines.is_empty() || match_regex.is_some() {
+        println!(
+            ":: Active matchers  : size={:?} words={:?} lines={:?} regex={:?}",
+            match_size, match_words, match_lines, config.match_regex
+        );
     }
-    let client = client_builder.build().unwrap();
 
-    let headers = config.headers.clone().unwrap_or_default();
-    let cookies = config.cookies.clone().unwrap_or_default();
-    let auth_token = config.auth_token.clone();
+    let results = Arc::new(Mutex::new(initial_results));
+    let queue = Arc::new(Mutex::new(queue));
+    let rate_limiter = (rate_per_sec > 0.0).then(|| RateLimiter::new(rate_per_sec, auto_throttle));
 
-    let results = tokio::sync::Mutex::new(Vec::new());
-    let semaphore = Arc::new(Semaphore::new(threads));
-
-    // The main fuzzing loop
-    stream::iter(targets)
-        .for_each_concurrent(threads, |target| {
-            let client = client.clone();
-            let status_codes = status_codes.clone();
-            let progress_bar = progress_bar.clone();
-            let headers = headers.clone();
-            let cookies = cookies.clone();
-            let auth_token = auth_token.clone();
-            let results = &results;
-            let semaphore = semaphore.clone();
-
-            async move {
-                let _permit = semaphore.acquire().await.unwrap();
-                if rate_limit > 0 {
-                    sleep(Duration::from_millis(rate_limit)).await;
+    // Checkpoint/journal handles: only set up when --export is given, since
+    // there's nothing to resume into otherwise. A fresh (non-resumed) run
+    // truncates any stale journal left by an abandoned earlier scan.
+    // `wordlist_progress` carries forward the indices already completed by a
+    // prior, resumed-from run so a second interruption checkpoints their union.
+    let wordlist_progress = export_path.as_ref().map(|_| Arc::new(Mutex::new(completed_indices.clone())));
+    let journal_handle = export_path.as_ref().map(|export| {
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(resumed)
+            .truncate(!resumed)
+            .open(journal_path(export))
+            .expect("Failed to open checkpoint journal");
+        Arc::new(Mutex::new(file))
+    });
+    let target_for_checkpoint = url.to_string();
+
+    // The main fuzzing loop: a fixed pool of `threads` workers drains the
+    // shared queue. Workers that find a directory push a fresh batch of
+    // jobs (the full wordlist under the new base) back onto the queue
+    // instead of just recording a hit, which is what makes the scan recursive.
+    let mut workers = Vec::with_capacity(threads);
+    for _ in 0..threads {
+        let client = client.clone();
+        let status_codes = status_codes.clone();
+        let progress_bar = progress_bar.clone();
+        let headers = headers.clone();
+        let auth_token = auth_token.clone();
+        let results = results.clone();
+        let calibration = calibration.clone();
+        let export_path = export_path.clone();
+        let wordlist_progress = wordlist_progress.clone();
+        let journal_handle = journal_handle.clone();
+        let target_for_checkpoint = target_for_checkpoint.clone();
+        let filter_size = filter_size.clone();
+        let filter_words = filter_words.clone();
+        let filter_lines = filter_lines.clone();
+        let filter_regex = filter_regex.clone();
+        let match_size = match_size.clone();
+        let match_words = match_words.clone();
+        let match_lines = match_lines.clone();
+        let match_regex = match_regex.clone();
+        let queue = queue.clone();
+        let in_flight = in_flight.clone();
+        let scanned_bases = scanned_bases.clone();
+        let words = words.clone();
+        let rate_limiter = rate_limiter.clone();
+
+        workers.push(tokio::spawn(async move {
+            loop {
+                let job = queue.lock().await.pop_front();
+                let job = match job {
+                    Some(job) => job,
+                    None => {
+                        if in_flight.load(Ordering::SeqCst) == 0 {
+                            break;
+                        }
+                        sleep(Duration::from_millis(10)).await;
+                        continue;
+                    }
+                };
+
+                if let Some(limiter) = &rate_limiter {
+                    limiter.acquire().await;
                 }
-                let word = target.split('/').last().unwrap_or("");
+
+                let request_start = Instant::now();
                 let res = fuzz_url_adv(
                     &client,
-                    &target,
-                    word,
+                    &job.target,
+                    &job.word,
                     &headers,
-                    &cookies,
                     auth_token.as_deref(),
+                    &job.method,
+                    job.body.as_ref(),
                 )
                 .await;
-                match &res {
-                    Ok((status, body)) => {
-                        let reflected = body.contains(word);
-                        let has_error = detect_error(body);
+                let response_time_ms = request_start.elapsed().as_millis() as u64;
+
+                'result: {
+                    match &res {
+                        Ok((status, body, final_url)) => {
+                            if let Some(limiter) = &rate_limiter {
+                                limiter.observe(*status);
+                            }
+                            let reflected = body.contains(&job.word);
+                            let has_error = detect_error(body);
+                            let sig = ResponseSignature::from_body(body);
+
+                            if recursion_depth > 0
+                                && job.depth < recursion_depth
+                                && looks_like_directory(&job.target, final_url, *status)
+                            {
+                                let new_base = job.target.trim_end_matches('/').to_string();
+                                let mut scanned = scanned_bases.lock().await;
+                                if scanned.insert(new_base.clone()) {
+                                    drop(scanned);
+                                    let new_jobs: Vec<ScanJob> = words
+                                        .iter()
+                                        .map(|w| ScanJob {
+                                            target: format!("{}/{}", new_base, w),
+                                            word: w.clone(),
+                                            depth: job.depth + 1,
+                                            method: "GET".to_string(),
+                                            body: None,
+                                            from_wordlist: false,
+                                            wordlist_index: None,
+                                        })
+                                        .collect();
+                                    in_flight.fetch_add(new_jobs.len(), Ordering::SeqCst);
+                                    progress_bar.inc_length(new_jobs.len() as u64);
+                                    queue.lock().await.extend(new_jobs);
+                                }
+                            }
+
+                            // Only print/export if status in --matcher
+                            if !status_codes.contains(status) {
+                                break 'result;
+                            }
+                            // Soft-404 auto-calibration: suppress hits that look like the
+                            // same "not found" page the calibration probes already saw.
+                            if matches_calibration(*status, &sig, &calibration) {
+                                break 'result;
+                            }
+                            // Explicit --filter-*: drop responses that match any value
+                            if filter_size.contains(&sig.content_length)
+                                || filter_words.contains(&sig.word_count)
+                                || filter_lines.contains(&sig.line_count)
+                                || filter_regex.as_ref().is_some_and(|re| re.is_match(body))
+                            {
+                                break 'result;
+                            }
+                            // Explicit --match-*: keep only responses that match every
+                            // constraint the user specified
+                            if !match_size.is_empty() && !match_size.contains(&sig.content_length) {
+                                break 'result;
+                            }
+                            if !match_words.is_empty() && !match_words.contains(&sig.word_count) {
+                                break 'result;
+                            }
+                            if !match_lines.is_empty() && !match_lines.contains(&sig.line_count) {
+                                break 'result;
+                            }
+                            if let Some(re) = match_regex.as_ref() {
+                                if !re.is_match(body) {
+                                    break 'result;
+                                }
+                            }
 
-                        // Only print/export if status in --matcher
-                        if status_codes.contains(status) {
                             println!(
-                                "{status} - {target}{}{}",
+                                "{} {status} - {}{}{}",
+                                job.method,
+                                job.target,
                                 if reflected { " [REFLECTED]" } else { "" },
                                 if has_error { " [ERROR]" } else { "" }
                             );
                             let r = FuzzResult {
-                                url: target.clone(),
-                                word: word.to_string(),
+                                url: job.target.clone(),
+                                word: job.word.clone(),
                                 status: *status,
                                 reflected,
                                 error: if has_error { Some("Possible error detected".into()) } else { None },
+                                content_length: sig.content_length,
+                                word_count: sig.word_count,
+                                line_count: sig.line_count,
+                                method: job.method.clone(),
+                                response_time_ms,
                             };
+                            // Append to the checkpoint journal (if --export is active) so
+                            // an interrupted run can be resumed without losing this hit.
+                            if let Some(journal) = &journal_handle {
+                                if let Ok(line) = serde_json::to_string(&r) {
+                                    use std::io::Write;
+                                    let mut file = journal.lock().await;
+                                    let _ = writeln!(file, "{line}");
+                                }
+                            }
                             results.lock().await.push(r);
                         }
+                        Err(_e) => {
+                            // Always show and export network errors
+                            // println!("ERR  - {} [error: {e}]", job.target);
+                            // let r = FuzzResult {
+                            //     url: job.target.clone(),
+                            //     word: job.word.clone(),
+                            //     status: 0,
+                            //     reflected: false,
+                            //     error: Some(e.to_string()),
+                            // };
+                            // results.lock().await.push(r);
+                        }
                     }
-                    Err(_e) => {
-                        // Always show and export network errors
-                        // println!("ERR  - {target} [error: {e}]");
-                        // let r = FuzzResult {
-                        //     url: target.clone(),
-                        //     word: word.to_string(),
-                        //     status: 0,
-                        //     reflected: false,
-                        //     error: Some(e.to_string()),
-                        // };
-                        // results.lock().await.push(r);
+                }
+
+                // Checkpoint: periodically persist the *set* of wordlist indices
+                // completed so far, so --resume can skip exactly those jobs by
+                // membership rather than trusting a count to be a safe prefix.
+                if let (true, Some(idx)) = (job.from_wordlist, job.wordlist_index) {
+                    if let Some(progress) = &wordlist_progress {
+                        let mut completed = progress.lock().await;
+                        completed.insert(idx);
+                        if completed.len() % 10 == 0 {
+                            if let Some(export) = &export_path {
+                                let mut completed_indices: Vec<usize> = completed.iter().copied().collect();
+                                completed_indices.sort_unstable();
+                                save_checkpoint(
+                                    &checkpoint_path(export),
+                                    &Checkpoint { target: target_for_checkpoint.clone(), wordlist_hash, completed_indices },
+                                );
+                            }
+                        }
                     }
                 }
+
                 progress_bar.inc(1);
+                in_flight.fetch_sub(1, Ordering::SeqCst);
             }
-        })
-        .await;
+        }));
+    }
+    for worker in workers {
+        let _ = worker.await;
+    }
 
     progress_bar.finish_with_message("Fuzzing complete!");
 
+    // Clean completion: the export written below now carries every result,
+    // so the checkpoint/journal sidecars no longer need to exist. Leaving a
+    // `.partial` behind is exactly what marks an interrupted run as resumable.
+    if let Some(export) = &export_path {
+        let _ = fs::remove_file(checkpoint_path(export));
+        let _ = fs::remove_file(journal_path(export));
+    }
+
+    // Persist the (possibly Set-Cookie-updated) jar back to --cookie-jar so a
+    // later run can resume the same session.
+    if let Some(jar_path) = &config.cookie_jar {
+        save_cookie_jar(jar_path, &cookie_jar);
+        println!(":: Cookie jar saved to {jar_path}");
+    }
+
     // Export results
     if let Some(export) = &config.export {
         let results = results.lock().await;
@@ -455,10 +1183,109 @@ async fn main() {
 
     // Analyze results if requested
     if let Some(analyze_file) = analyze_path {
-        analyze_results(&analyze_file).await;
+        if let Err(e) = analyze_results(&analyze_file, report_format, show_all).await {
+            eprintln!("Analysis failed: {e}");
+            std::process::exit(exit_code_for(&e));
+        }
     }
 }
 
+/// Distinct process exit codes per `RustfuzzError` variant, so scripts
+/// wrapping rustfuzz can tell an I/O problem apart from a bad input file
+/// without scraping stderr text.
+fn exit_code_for(err: &RustfuzzError) -> i32 {
+    match err {
+        RustfuzzError::Io(_) => 2,
+        RustfuzzError::Csv(_) => 3,
+        RustfuzzError::Json(_) => 4,
+        RustfuzzError::UnsupportedFormat(_) => 5,
+    }
+}
+
+/// Load a persisted cookie jar from disk. `.json` files are read with
+/// `cookie_store`'s native format; anything else is treated as a Netscape
+/// cookie file (the format curl/wget use) for compatibility with jars
+/// exported by other tools. Missing or unreadable files just yield an empty
+/// jar so `--cookie-jar` can be pointed at a not-yet-existing path.
+fn load_cookie_jar(path: &str) -> CookieStore {
+    if path.ends_with(".json") {
+        match fs::File::open(path) {
+            Ok(file) => CookieStore::load_json(std::io::BufReader::new(file)).unwrap_or_default(),
+            Err(_) => CookieStore::default(),
+        }
+    } else {
+        load_netscape_cookie_jar(path)
+    }
+}
+
+/// Persist the jar back to `path`, mirroring the format `load_cookie_jar`
+/// would read from that same path.
+fn save_cookie_jar(path: &str, jar: &CookieStoreMutex) {
+    let store = jar.lock().unwrap();
+    if path.ends_with(".json") {
+        if let Ok(file) = fs::File::create(path) {
+            let _ = store.save_json(&mut std::io::BufWriter::new(file));
+        }
+    } else {
+        save_netscape_cookie_jar(path, &store);
+    }
+}
+
+/// Parse a Netscape-format cookie file: tab-separated
+/// `domain  include_subdomains  path  secure  expiry  name  value`, one
+/// cookie per line, `#`-prefixed lines and blanks ignored.
+fn load_netscape_cookie_jar(path: &str) -> CookieStore {
+    let mut store = CookieStore::default();
+    let Ok(contents) = fs::read_to_string(path) else {
+        return store;
+    };
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split('\t').collect();
+        if fields.len() != 7 {
+            continue;
+        }
+        let domain = fields[0].trim_start_matches('.');
+        let path_field = fields[2];
+        let name = fields[5];
+        let value = fields[6];
+        let Ok(url) = Url::parse(&format!("https://{domain}{path_field}")) else {
+            continue;
+        };
+        if let Ok(cookie) = cookie::Cookie::parse(format!("{name}={value}")) {
+            let _ = store.insert_raw(&cookie, &url);
+        }
+    }
+    store
+}
+
+/// Write the jar out in Netscape format so it can be read back by
+/// `load_netscape_cookie_jar` (or by curl/wget).
+fn save_netscape_cookie_jar(path: &str, store: &CookieStore) {
+    let mut out = String::from("# Netscape HTTP Cookie File\n");
+    for cookie in store.iter_unexpired() {
+        let domain = cookie.domain().unwrap_or("");
+        let expiry = cookie
+            .expires()
+            .and_then(|e| e.datetime())
+            .map(|dt| dt.unix_timestamp())
+            .unwrap_or(0);
+        out.push_str(&format!(
+            "{}\tTRUE\t{}\t{}\t{}\t{}\t{}\n",
+            domain,
+            cookie.path().unwrap_or("/"),
+            if cookie.secure().unwrap_or(false) { "TRUE" } else { "FALSE" },
+            expiry,
+            cookie.name(),
+            cookie.value(),
+        ));
+    }
+    let _ = fs::write(path, out);
+}
+
 async fn load_wordlist(path: &str) -> io::Result<Vec<String>> {
     let file = File::open(path).await?;
     let reader = BufReader::new(file);
@@ -497,28 +1324,44 @@ async fn fuzz_url_adv(
     url: &str,
     _word: &str,
     headers: &Vec<(String, String)>,
-    cookies: &Vec<(String, String)>,
     auth_token: Option<&str>,
-) -> Result<(u16, String), reqwest::Error> {
-    let mut req = client.get(url);
+    method: &str,
+    body: Option<&serde_json::Value>,
+) -> Result<(u16, String, String), reqwest::Error> {
+    // Cookies are not attached here: the client's cookie store (shared with
+    // the crawler) tracks Set-Cookie responses and replays them automatically.
+    let http_method = method.parse::<reqwest::Method>().unwrap_or(reqwest::Method::GET);
+    let mut req = client.request(http_method, url);
     for (k, v) in headers {
         req = req.header(k, v);
     }
-    if !cookies.is_empty() {
-        let cookie_str = cookies
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, v))
-            .collect::<Vec<_>>()
-            .join("; ");
-        req = req.header("Cookie", cookie_str);
-    }
     if let Some(token) = auth_token {
         req = req.bearer_auth(token);
     }
+    if let Some(body) = body {
+        req = req.json(body);
+    }
     let resp = req.send().await?;
     let status = resp.status().as_u16();
+    let final_url = resp.url().as_str().to_string();
     let body = resp.text().await.unwrap_or_default();
-    Ok((status, body))
+    Ok((status, body, final_url))
+}
+
+/// True if this response looks like it hit a directory rather than a leaf
+/// file: either the server redirected to the same path with a trailing slash
+/// appended (the classic Apache/nginx "this is a directory" redirect), or it
+/// answered 200 on a path with no file extension.
+fn looks_like_directory(requested_url: &str, final_url: &str, status: u16) -> bool {
+    if final_url != requested_url {
+        return final_url.trim_end_matches('/') == requested_url.trim_end_matches('/')
+            && final_url.ends_with('/');
+    }
+    if status == 200 {
+        let last_segment = requested_url.rsplit('/').next().unwrap_or("");
+        return !last_segment.contains('.');
+    }
+    false
 }
 
 fn detect_error(body: &str) -> bool {
@@ -538,19 +1381,59 @@ fn detect_error(body: &str) -> bool {
     re.is_match(&body.to_lowercase())
 }
 
-/// Smart Crawler: BFS, supports cookies, headers, domain limit, ignores non-HTML, deduplicates, detects API/REST endpoints.
+/// What a response body actually looks like, sniffed from its leading bytes
+/// rather than trusted from a (possibly missing or wrong) Content-Type
+/// header.
+#[derive(PartialEq)]
+enum SniffedKind {
+    Html,
+    Json,
+    Opaque,
+}
+
+/// Classify a body by content rather than header: HTML is recognised by an
+/// early `<!doctype html`/`<html`/`<head`/`<body` marker, JSON by a leading
+/// `{`/`[` that actually parses. Anything else is opaque and not crawled.
+fn sniff_content(body: &str) -> SniffedKind {
+    let head: String = body.trim_start().chars().take(512).collect::<String>().to_lowercase();
+    if head.contains("<!doctype html") || head.contains("<html") || head.contains("<head") || head.contains("<body") {
+        return SniffedKind::Html;
+    }
+    let trimmed = body.trim_start();
+    if (trimmed.starts_with('{') || trimmed.starts_with('['))
+        && serde_json::from_str::<serde_json::Value>(trimmed).is_ok()
+    {
+        return SniffedKind::Json;
+    }
+    SniffedKind::Opaque
+}
+
+/// Pull URL-like strings out of a JSON body: absolute URLs, absolute paths,
+/// and OpenAPI-style `"$ref"` pointers. The href regex used for HTML can't
+/// see these, so API responses (and embedded specs) would otherwise be
+/// invisible to the crawler.
+fn extract_json_endpoints(body: &str) -> Vec<String> {
+    let re = Regex::new(r#""((?:https?://|/|#/)[^"\s]*)""#).unwrap();
+    re.captures_iter(body)
+        .filter_map(|cap| cap.get(1).map(|m| m.as_str().to_string()))
+        .collect()
+}
+
+/// Smart Crawler: BFS, shares the caller's `Client` (and thus its cookie
+/// store) so Set-Cookie responses seen while crawling are replayed on later
+/// requests, headers, domain limit, sniffs body content instead of trusting
+/// Content-Type, deduplicates, detects API/REST endpoints.
 pub async fn crawl(
     base_url: &str,
     max_depth: usize,
     max_pages: usize,
-    cookies: &Vec<(String, String)>,
     headers: &Vec<(String, String)>,
+    client: Client,
 ) -> HashSet<String> {
     let mut found = HashSet::new();
     let mut visited = HashSet::new();
     let mut queue = VecDeque::new();
 
-    let client = Client::new();
     let base_url = match Url::parse(base_url) {
         Ok(u) => u,
         Err(_) => return found,
@@ -563,52 +1446,34 @@ pub async fn crawl(
     let href_re = Regex::new(r#"href\s*=\s*["']([^"'>]+)["']"#).unwrap();
     let api_re = Regex::new(r#"(api|rest|openapi|swagger|v\d+)"#).unwrap();
 
-    // Prepare cookie/header string if needed
-    let cookie_str = if !cookies.is_empty() {
-        Some(
-            cookies
-                .iter()
-                .map(|(k, v)| format!("{}={}", k, v))
-                .collect::<Vec<_>>()
-                .join("; ")
-        )
-    } else {
-        None
-    };
-
     while let Some((url, depth)) = queue.pop_front() {
         if depth > max_depth || found.len() >= max_pages {
             break;
         }
 
         let mut req = client.get(url.as_str());
-        if let Some(ref c) = cookie_str {
-            req = req.header("Cookie", c);
-        }
         for (k, v) in headers {
             req = req.header(k, v);
         }
 
         let body = match req.send().await {
-            Ok(resp) => {
-                // Ignore non-HTML responses (e.g., images, PDFs)
-                let content_type = resp.headers().get("content-type")
-                    .and_then(|val| val.to_str().ok())
-                    .unwrap_or("");
-                if !content_type.starts_with("text/html") {
-                    continue;
-                }
-                match resp.text().await {
-                    Ok(txt) => txt,
-                    Err(_) => continue,
-                }
-            }
+            Ok(resp) => match resp.text().await {
+                Ok(txt) => txt,
+                Err(_) => continue,
+            },
             Err(_) => continue,
         };
 
-        for cap in href_re.captures_iter(&body) {
-            let href = cap.get(1).unwrap().as_str();
+        let candidates: Vec<String> = match sniff_content(&body) {
+            SniffedKind::Html => href_re
+                .captures_iter(&body)
+                .filter_map(|cap| cap.get(1).map(|m| m.as_str().to_string()))
+                .collect(),
+            SniffedKind::Json => extract_json_endpoints(&body),
+            SniffedKind::Opaque => continue,
+        };
 
+        for href in &candidates {
             // Ignore fragments, mailto, javascript, tel, data URIs, etc.
             if href.starts_with('#')
                 || href.starts_with("mailto:")
@@ -665,94 +1530,953 @@ pub async fn crawl(
     found
 }
 
-async fn parse_openapi(_url: &str) -> HashSet<String> {
-    let mut s = HashSet::new();
-    s.insert("https://example.com/api/v1/users".to_string());
-    s.insert("https://example.com/api/v1/login".to_string());
-    s
+/// A concrete, ready-to-fire request derived from one (path, method) pair in
+/// an OpenAPI/Swagger spec: path parameters substituted with sample values,
+/// query parameters appended, and a sample JSON body built from the
+/// declared request-body schema (if any).
+#[derive(Debug, Clone)]
+struct ApiEndpoint {
+    url: String,
+    method: String,
+    body: Option<serde_json::Value>,
 }
 
-/// Analyze and beautifully print the results from JSON or CSV export file
-async fn analyze_results(path: &str) {
-    println!(":: Analyzing results from {path}");
-    let mut results: Vec<FuzzResult> = Vec::new();
-
-    if path.ends_with(".json") {
-        let file_content = match fs::read_to_string(path) {
-            Ok(s) => s,
+/// Fetches (http/https URL) or reads (local path) an OpenAPI 3.x or Swagger
+/// 2.0 spec and turns every declared GET/POST/PUT/PATCH/DELETE operation into a fuzzable
+/// `ApiEndpoint`, so `--openapi` drives real API fuzzing instead of GET-only
+/// guessing.
+async fn parse_openapi(source: &str) -> Vec<ApiEndpoint> {
+    let spec_text = if source.starts_with("http://") || source.starts_with("https://") {
+        match reqwest::get(source).await {
+            Ok(resp) => resp.text().await.unwrap_or_default(),
             Err(e) => {
-                eprintln!("Failed to read file: {e}");
-                return;
+                eprintln!("Failed to fetch OpenAPI spec from {source}: {e}");
+                return Vec::new();
             }
-        };
-        results = match serde_json::from_str(&file_content) {
-            Ok(v) => v,
+        }
+    } else {
+        match fs::read_to_string(source) {
+            Ok(s) => s,
             Err(e) => {
-                eprintln!("Failed to parse JSON: {e}");
-                return;
+                eprintln!("Failed to read OpenAPI spec file {source}: {e}");
+                return Vec::new();
             }
-        };
-    } else if path.ends_with(".csv") {
-        let mut rdr = match csv::Reader::from_path(path) {
-            Ok(r) => r,
-            Err(e) => {
-                eprintln!("Failed to open CSV: {e}");
-                return;
+        }
+    };
+
+    let spec: serde_json::Value = match serde_json::from_str(&spec_text) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("Failed to parse OpenAPI spec as JSON: {e}");
+            return Vec::new();
+        }
+    };
+
+    let is_swagger2 = spec.get("swagger").and_then(|v| v.as_str()) == Some("2.0");
+    let base = openapi_base_url(&spec, source, is_swagger2);
+
+    let Some(paths) = spec.get("paths").and_then(|p| p.as_object()) else {
+        eprintln!("OpenAPI spec has no `paths` object");
+        return Vec::new();
+    };
+
+    let mut endpoints = Vec::new();
+    for (path_template, path_item) in paths {
+        let Some(path_item) = path_item.as_object() else { continue };
+
+        // Parameters declared at the path-item level apply to every method underneath.
+        let shared_params: Vec<serde_json::Value> = path_item
+            .get("parameters")
+            .and_then(|p| p.as_array())
+            .cloned()
+            .unwrap_or_default();
+
+        for method in ["get", "post", "put", "patch", "delete"] {
+            let Some(op) = path_item.get(method).and_then(|m| m.as_object()) else { continue };
+
+            let mut params = shared_params.clone();
+            if let Some(op_params) = op.get("parameters").and_then(|p| p.as_array()) {
+                params.extend(op_params.iter().cloned());
             }
-        };
-        for result in rdr.deserialize() {
-            match result {
-                Ok(r) => results.push(r),
-                Err(e) => {
-                    eprintln!("Failed to deserialize row: {e}");
+
+            let resolved_path = resolve_path_params(path_template, &params);
+            let query = build_query_string(&params);
+            let url = format!("{}{}{}", base.trim_end_matches('/'), resolved_path, query);
+
+            endpoints.push(ApiEndpoint {
+                url,
+                method: method.to_uppercase(),
+                body: extract_sample_body(op, is_swagger2),
+            });
+        }
+    }
+
+    endpoints
+}
+
+/// Picks the base URL requests are resolved against: Swagger 2.0's
+/// `schemes`/`host`/`basePath` trio, OpenAPI 3.x's first `servers` entry, or
+/// (failing both) the directory the spec itself was loaded from.
+fn openapi_base_url(spec: &serde_json::Value, source: &str, is_swagger2: bool) -> String {
+    if is_swagger2 {
+        if let Some(host) = spec.get("host").and_then(|v| v.as_str()) {
+            let scheme = spec
+                .get("schemes")
+                .and_then(|s| s.as_array())
+                .and_then(|a| a.first())
+                .and_then(|v| v.as_str())
+                .unwrap_or("https");
+            let base_path = spec.get("basePath").and_then(|v| v.as_str()).unwrap_or("");
+            return format!("{scheme}://{host}{base_path}");
+        }
+    } else if let Some(server_url) = spec
+        .get("servers")
+        .and_then(|s| s.as_array())
+        .and_then(|a| a.first())
+        .and_then(|s| s.get("url"))
+        .and_then(|u| u.as_str())
+    {
+        return server_url.trim_end_matches('/').to_string();
+    }
+
+    match source.rfind('/') {
+        Some(idx) => source[..idx].to_string(),
+        None => source.to_string(),
+    }
+}
+
+/// Substitutes every `{name}` path-parameter placeholder in `template` with a
+/// sample value drawn from the matching parameter's `example`/`default`/`enum`
+/// (falling back to a type-appropriate placeholder).
+fn resolve_path_params(template: &str, params: &[serde_json::Value]) -> String {
+    let mut resolved = template.to_string();
+    for param in params {
+        let Some(obj) = param.as_object() else { continue };
+        if obj.get("in").and_then(|v| v.as_str()) != Some("path") {
+            continue;
+        }
+        let Some(name) = obj.get("name").and_then(|v| v.as_str()) else { continue };
+        let placeholder = format!("{{{name}}}");
+        if resolved.contains(&placeholder) {
+            resolved = resolved.replace(&placeholder, &sample_param_value(obj));
+        }
+    }
+    resolved
+}
+
+/// Builds a `?k=v&...` query string from every `in: query` parameter.
+fn build_query_string(params: &[serde_json::Value]) -> String {
+    let pairs: Vec<String> = params
+        .iter()
+        .filter_map(|p| {
+            let obj = p.as_object()?;
+            if obj.get("in").and_then(|v| v.as_str()) != Some("query") {
+                return None;
+            }
+            let name = obj.get("name").and_then(|v| v.as_str())?;
+            Some(format!("{}={}", name, sample_param_value(obj)))
+        })
+        .collect();
+    if pairs.is_empty() {
+        String::new()
+    } else {
+        format!("?{}", pairs.join("&"))
+    }
+}
+
+/// Picks a sample value for a single parameter: its own (or, for OpenAPI 3.x,
+/// its nested `schema`'s) `example`/`default`/`enum`, falling back to a
+/// type-appropriate placeholder.
+fn sample_param_value(param: &serde_json::Map<String, serde_json::Value>) -> String {
+    if let Some(v) = pick_sample(param) {
+        return json_scalar_to_string(v);
+    }
+    if let Some(schema) = param.get("schema").and_then(|s| s.as_object()) {
+        if let Some(v) = pick_sample(schema) {
+            return json_scalar_to_string(v);
+        }
+        return placeholder_for_type(schema.get("type").and_then(|t| t.as_str()));
+    }
+    placeholder_for_type(param.get("type").and_then(|t| t.as_str()))
+}
+
+fn pick_sample(obj: &serde_json::Map<String, serde_json::Value>) -> Option<&serde_json::Value> {
+    obj.get("example")
+        .or_else(|| obj.get("default"))
+        .or_else(|| obj.get("enum").and_then(|e| e.as_array()).and_then(|a| a.first()))
+}
+
+fn placeholder_for_type(ty: Option<&str>) -> String {
+    match ty {
+        Some("integer") | Some("number") => "1".to_string(),
+        Some("boolean") => "true".to_string(),
+        _ => "test".to_string(),
+    }
+}
+
+fn json_scalar_to_string(v: &serde_json::Value) -> String {
+    match v {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Builds a sample JSON request body for an operation's request-body schema:
+/// Swagger 2.0's `in: body` parameter, or OpenAPI 3.x's
+/// `requestBody.content["application/json"].schema`.
+fn extract_sample_body(op: &serde_json::Map<String, serde_json::Value>, is_swagger2: bool) -> Option<serde_json::Value> {
+    let schema = if is_swagger2 {
+        op.get("parameters")?
+            .as_array()?
+            .iter()
+            .find(|p| p.get("in").and_then(|v| v.as_str()) == Some("body"))?
+            .get("schema")?
+    } else {
+        op.get("requestBody")?
+            .get("content")?
+            .get("application/json")?
+            .get("schema")?
+    };
+    Some(sample_from_schema(schema))
+}
+
+/// Recursively builds a sample JSON value from a (possibly nested) JSON
+/// Schema fragment: objects get one sample value per declared property,
+/// arrays get a single sample item, and scalars get a type-appropriate
+/// placeholder. `$ref` schemas are not resolved; they fall through to "test".
+fn sample_from_schema(schema: &serde_json::Value) -> serde_json::Value {
+    let Some(obj) = schema.as_object() else { return serde_json::Value::Null };
+    if let Some(example) = obj.get("example") {
+        return example.clone();
+    }
+    if let Some(default) = obj.get("default") {
+        return default.clone();
+    }
+    if let Some(first) = obj.get("enum").and_then(|e| e.as_array()).and_then(|a| a.first()) {
+        return first.clone();
+    }
+    match obj.get("type").and_then(|t| t.as_str()) {
+        Some("object") => {
+            let mut map = serde_json::Map::new();
+            if let Some(props) = obj.get("properties").and_then(|p| p.as_object()) {
+                for (key, prop_schema) in props {
+                    map.insert(key.clone(), sample_from_schema(prop_schema));
                 }
             }
+            serde_json::Value::Object(map)
+        }
+        Some("array") => {
+            let item = obj.get("items").map(sample_from_schema).unwrap_or(serde_json::Value::Null);
+            serde_json::Value::Array(vec![item])
+        }
+        Some("integer") | Some("number") => serde_json::json!(1),
+        Some("boolean") => serde_json::json!(true),
+        _ => serde_json::json!("test"),
+    }
+}
+
+/// How `--analyze` should render a result set.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum OutputFormat {
+    Console,
+    Markdown,
+    Html,
+}
+
+impl OutputFormat {
+    fn parse(spec: Option<&str>) -> Self {
+        match spec.map(|s| s.to_lowercase()).as_deref() {
+            Some("markdown") | Some("md") => OutputFormat::Markdown,
+            Some("html") => OutputFormat::Html,
+            _ => OutputFormat::Console,
+        }
+    }
+
+    /// File extension a rendered report of this format should be saved with.
+    fn extension(self) -> &'static str {
+        match self {
+            OutputFormat::Markdown => "md",
+            OutputFormat::Html => "html",
+            OutputFormat::Console => "",
+        }
+    }
+}
+
+/// Swap the input file's extension for the report format's own, so
+/// `results.json --output-format html` lands at `results.html`.
+fn report_output_path(input: &str, format: OutputFormat) -> String {
+    match input.rfind('.') {
+        Some(idx) => format!("{}.{}", &input[..idx], format.extension()),
+        None => format!("{input}.{}", format.extension()),
+    }
+}
+
+fn status_class(status: u16) -> &'static str {
+    match status {
+        200..=299 => "status-2xx",
+        300..=399 => "status-3xx",
+        400..=499 => "status-4xx",
+        500..=599 => "status-5xx",
+        _ => "status-other",
+    }
+}
+
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// A GitHub-renderable Markdown report: summary counts table plus a results
+/// table with every row passed in (unlike the console view, no 20-row cut or
+/// column truncation). When the dataset outgrew the analysis sample, those
+/// rows are a random sample and the report says so.
+fn render_markdown_report(summary: &Summary, results: &[FuzzResult], sample_of: Option<usize>) -> String {
+    let mut out = String::new();
+    out.push_str("# rustfuzz Report\n\n");
+    if let Some(total_rows) = sample_of {
+        out.push_str(&format!(
+            "> Dataset has {total_rows} rows; the table below covers a {}-row random sample. Summary counts below are exact over the full dataset.\n\n",
+            results.len()
+        ));
+    }
+    out.push_str("## Summary\n\n");
+    out.push_str("| Metric | Count |\n|---|---|\n");
+    out.push_str(&format!("| Total | {} |\n", summary.total));
+    out.push_str(&format!("| 2xx Success | {} |\n", summary.success));
+    out.push_str(&format!("| 3xx Redirects | {} |\n", summary.redirects));
+    out.push_str(&format!("| 4xx Client Errors | {} |\n", summary.client_err));
+    out.push_str(&format!("| 5xx Server Errors | {} |\n", summary.server_err));
+    out.push_str(&format!("| Reflected | {} |\n", summary.reflected));
+    out.push_str(&format!("| Errors | {} |\n\n", summary.errors));
+
+    out.push_str("## Results\n\n");
+    out.push_str("| Method | Code | URL | Word | Size | Words | Lines | Reflected | Error |\n");
+    out.push_str("|---|---|---|---|---|---|---|---|---|\n");
+    for r in results {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} | {} | {} | {} |\n",
+            r.method,
+            r.status,
+            r.url,
+            r.word,
+            r.content_length,
+            r.word_count,
+            r.line_count,
+            if r.reflected { "yes" } else { "" },
+            r.error.as_deref().unwrap_or(""),
+        ));
+    }
+    out
+}
+
+/// A self-contained HTML report: the same summary counts and a sortable (click
+/// a header) results table with status-code-colored rows holding every row
+/// passed in. When the dataset outgrew the analysis sample, those rows are a
+/// random sample and the report says so.
+fn render_html_report(summary: &Summary, results: &[FuzzResult], sample_of: Option<usize>) -> String {
+    let total = summary.total;
+    let success = summary.success;
+    let redirects = summary.redirects;
+    let client_err = summary.client_err;
+    let server_err = summary.server_err;
+    let reflected = summary.reflected;
+    let errors = summary.errors;
+
+    let sample_note = match sample_of {
+        Some(total_rows) => format!(
+            "<p class=\"sample-note\">Dataset has {total_rows} rows; the table below covers a {}-row random sample. Summary counts below are exact over the full dataset.</p>\n",
+            results.len()
+        ),
+        None => String::new(),
+    };
+
+    let mut rows = String::new();
+    for r in results {
+        rows.push_str(&format!(
+            "<tr class=\"{}\"><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            status_class(r.status),
+            html_escape(&r.method),
+            r.status,
+            html_escape(&r.url),
+            html_escape(&r.word),
+            r.content_length,
+            r.word_count,
+            r.line_count,
+            if r.reflected { "yes" } else { "" },
+            r.error.as_deref().map(html_escape).unwrap_or_default(),
+        ));
+    }
+
+    format!(
+        r#"<!doctype html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>rustfuzz Report</title>
+<style>
+body {{ font-family: sans-serif; margin: 2rem; }}
+table {{ border-collapse: collapse; width: 100%; margin-bottom: 2rem; }}
+th, td {{ border: 1px solid #ccc; padding: 4px 8px; text-align: left; }}
+#results th {{ cursor: pointer; background: #222; color: #fff; }}
+.status-2xx {{ background: #e6ffed; }}
+.status-3xx {{ background: #fff8e1; }}
+.status-4xx {{ background: #ffe6e6; }}
+.status-5xx {{ background: #ffd1d1; }}
+</style>
+</head>
+<body>
+<h1>rustfuzz Report</h1>
+{sample_note}<table id="summary">
+<tr><th>Total</th><td>{total}</td></tr>
+<tr><th>2xx Success</th><td>{success}</td></tr>
+<tr><th>3xx Redirects</th><td>{redirects}</td></tr>
+<tr><th>4xx Client Errors</th><td>{client_err}</td></tr>
+<tr><th>5xx Server Errors</th><td>{server_err}</td></tr>
+<tr><th>Reflected</th><td>{reflected}</td></tr>
+<tr><th>Errors</th><td>{errors}</td></tr>
+</table>
+<h2>Results</h2>
+<table id="results">
+<thead><tr><th>Method</th><th>Code</th><th>URL</th><th>Word</th><th>Size</th><th>Words</th><th>Lines</th><th>Reflected</th><th>Error</th></tr></thead>
+<tbody>
+{rows}</tbody>
+</table>
+<script>
+document.querySelectorAll('#results th').forEach((th, i) => {{
+  th.addEventListener('click', () => {{
+    const tbody = th.closest('table').querySelector('tbody');
+    const bodyRows = Array.from(tbody.querySelectorAll('tr'));
+    const asc = th.dataset.asc !== 'true';
+    bodyRows.sort((a, b) => {{
+      const av = a.children[i].innerText, bv = b.children[i].innerText;
+      const an = parseFloat(av), bn = parseFloat(bv);
+      const cmp = (!isNaN(an) && !isNaN(bn)) ? an - bn : av.localeCompare(bv);
+      return asc ? cmp : -cmp;
+    }});
+    th.dataset.asc = asc;
+    bodyRows.forEach(r => tbody.appendChild(r));
+  }});
+}});
+</script>
+</body>
+</html>
+"#
+    )
+}
+
+/// Aggregate counts produced by analyzing a result set. Returned from
+/// `analyze_results` (rather than only printed) so a caller can act on them
+/// programmatically.
+#[derive(Debug, Clone, Default)]
+struct Summary {
+    total: usize,
+    success: usize,
+    redirects: usize,
+    client_err: usize,
+    server_err: usize,
+    reflected: usize,
+    errors: usize,
+    /// Rows that failed to deserialize (CSV/JSONL only) and were skipped.
+    row_errors: usize,
+    critical_findings: usize,
+    notable_findings: usize,
+    /// Results that matched an inferred soft-404 baseline and were excluded
+    /// from every count above (unless `--show-all` was given).
+    filtered: usize,
+    baselines: Vec<InferredBaseline>,
+}
+
+impl Summary {
+    fn from_tally(tally: &StreamingTally, findings: &[Finding]) -> Self {
+        Summary {
+            total: tally.total,
+            success: tally.success,
+            redirects: tally.redirects,
+            client_err: tally.client_err,
+            server_err: tally.server_err,
+            reflected: tally.reflected,
+            errors: tally.errors,
+            row_errors: tally.row_errors,
+            critical_findings: findings.iter().filter(|f| f.level == Level::Critical).count(),
+            notable_findings: findings.iter().filter(|f| f.level == Level::Notable).count(),
+            filtered: 0,
+            baselines: Vec::new(),
+        }
+    }
+}
+
+/// Running per-status-class/reflected/error counters updated one record at a
+/// time as `analyze_results` streams through the input file, so the summary
+/// counts stay exact without ever holding the whole result set in memory.
+#[derive(Debug, Default)]
+struct StreamingTally {
+    total: usize,
+    success: usize,
+    redirects: usize,
+    client_err: usize,
+    server_err: usize,
+    reflected: usize,
+    errors: usize,
+    row_errors: usize,
+}
+
+impl StreamingTally {
+    fn record(&mut self, r: &FuzzResult) {
+        self.total += 1;
+        match r.status {
+            200..=299 => self.success += 1,
+            300..=399 => self.redirects += 1,
+            400..=499 => self.client_err += 1,
+            500..=599 => self.server_err += 1,
+            _ => {}
+        }
+        if r.reflected {
+            self.reflected += 1;
+        }
+        if r.error.is_some() {
+            self.errors += 1;
+        }
+    }
+}
+
+/// Upper bound on how many records `analyze_results` keeps in memory at
+/// once (for the preview table/report and severity scoring). Everything
+/// else — the summary counts above and the baseline inference — is tallied
+/// via streaming passes instead, so total memory stays roughly constant no matter
+/// how many rows the input file actually has.
+const ANALYSIS_SAMPLE_SIZE: usize = 5_000;
+
+/// Reservoir sampler (Algorithm R): maintains a uniform-random sample of up
+/// to `capacity` items drawn from a stream of unknown length, in O(capacity)
+/// memory regardless of how many items are offered. While the stream is
+/// still shorter than `capacity`, every item is kept and order is preserved.
+struct Reservoir<T> {
+    capacity: usize,
+    seen: usize,
+    sample: Vec<T>,
+}
+
+impl<T> Reservoir<T> {
+    fn new(capacity: usize) -> Self {
+        Reservoir { capacity, seen: 0, sample: Vec::with_capacity(capacity) }
+    }
+
+    fn offer(&mut self, item: T) {
+        self.seen += 1;
+        if self.sample.len() < self.capacity {
+            self.sample.push(item);
+        } else {
+            let j = thread_rng().gen_range(0..self.seen);
+            if j < self.capacity {
+                self.sample[j] = item;
+            }
         }
+    }
+}
+
+/// A noise baseline inferred from a saved result set by grouping on
+/// `(status, content_length, word_count, line_count)`: a group that accounts
+/// for a large share of all results looks like the same "soft 404" page
+/// repeated under many words, analogous to what a live `calibrate()` run
+/// would have flagged had the scan itself run with calibration enabled.
+#[derive(Debug, Clone)]
+struct InferredBaseline {
+    status: u16,
+    content_length: usize,
+    word_count: usize,
+    line_count: usize,
+    count: usize,
+}
+
+/// A group must appear at least this many times before it's eligible to be
+/// treated as baseline noise rather than a handful of coincidentally
+/// identical real hits.
+const BASELINE_MIN_COUNT: usize = 3;
+
+/// ...and it must account for at least this share of all results.
+const BASELINE_MIN_SHARE: f64 = 0.2;
+
+/// Derive baseline signatures from a `(status, size, words, lines) -> count`
+/// distribution gathered over an entire result set (see `InferredBaseline`).
+/// Takes the already-aggregated counts, rather than the records themselves,
+/// so the caller can build them with a single streaming pass over a file far
+/// too large to hold in memory as a `Vec<FuzzResult>`.
+fn infer_baselines(total: usize, groups: HashMap<(u16, usize, usize, usize), usize>) -> Vec<InferredBaseline> {
+    let total = total as f64;
+    let mut baselines: Vec<InferredBaseline> = groups
+        .into_iter()
+        .filter(|(_, count)| *count >= BASELINE_MIN_COUNT && *count as f64 / total >= BASELINE_MIN_SHARE)
+        .map(|((status, content_length, word_count, line_count), count)| InferredBaseline {
+            status,
+            content_length,
+            word_count,
+            line_count,
+            count,
+        })
+        .collect();
+    baselines.sort_by(|a, b| b.count.cmp(&a.count));
+    baselines
+}
+
+/// True if `r` matches one of the inferred baseline signatures.
+fn matches_baseline(r: &FuzzResult, baselines: &[InferredBaseline]) -> bool {
+    baselines.iter().any(|b| {
+        b.status == r.status
+            && b.content_length == r.content_length
+            && b.word_count == r.word_count
+            && b.line_count == r.line_count
+    })
+}
+
+/// Ordered severity for a `Finding`. Declared low-to-high so derived `Ord`
+/// sorts `Critical` above `Notable` above `Info` once reversed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Level {
+    Info,
+    Notable,
+    Critical,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Critical => write!(f, "CRITICAL"),
+            Level::Notable => write!(f, "NOTABLE"),
+            Level::Info => write!(f, "INFO"),
+        }
+    }
+}
+
+/// A single scored result surfaced by `score_findings`. `level` buckets the
+/// finding for the ranked listing; `score` breaks ties within a level
+/// (higher means a more anomalous outlier) and `reason` is the one-line
+/// justification printed alongside it.
+#[derive(Debug, Clone)]
+struct Finding {
+    index: usize,
+    level: Level,
+    score: f64,
+    reason: String,
+}
+
+/// Modified z-score (see `mad_score`) a content-length or response-time
+/// value must reach before it's flagged as an outlier, i.e. about 5.2
+/// median-absolute-deviations from the median. ~3.5 is the commonly cited
+/// threshold for the modified z-score.
+const OUTLIER_MAD_THRESHOLD: f64 = 3.5;
+
+/// Share of results that must share one status code before that code counts
+/// as the "dominant" baseline used to escalate 401/403 findings.
+const DOMINANT_STATUS_RATIO: f64 = 0.5;
+
+fn median(values: &mut [f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mid = values.len() / 2;
+    if values.len() % 2 == 0 {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    }
+}
+
+/// Modified z-score: 0.6745 times the number of median-absolute-deviations
+/// `value` sits from `center`. A zero MAD (e.g. every result is the same size) treats any
+/// departure from `center` as maximally anomalous rather than dividing by zero.
+fn mad_score(value: f64, center: f64, mad: f64) -> f64 {
+    if mad == 0.0 {
+        if value == center { 0.0 } else { f64::MAX }
     } else {
-        eprintln!("Unsupported file type for analysis: {path}");
-        return;
+        0.6745 * (value - center).abs() / mad
     }
+}
 
+/// Rank every result by severity, highest-signal first: `reflected` hits and
+/// 5xx responses always escalate to `Critical`; content-length or
+/// response-time outliers (by median-absolute-deviation) and 401/403 on a
+/// 404-dominant target escalate to `Notable`. Everything else is left
+/// unflagged (no `Finding` is produced) so the ranked list stays short.
+fn score_findings(results: &[FuzzResult]) -> Vec<Finding> {
     if results.is_empty() {
+        return Vec::new();
+    }
+
+    let mut lengths: Vec<f64> = results.iter().map(|r| r.content_length as f64).collect();
+    let length_median = median(&mut lengths.clone());
+    let length_mad = median(&mut lengths.iter().map(|v| (v - length_median).abs()).collect::<Vec<_>>());
+
+    let mut times: Vec<f64> = results.iter().map(|r| r.response_time_ms as f64).collect();
+    let time_median = median(&mut times.clone());
+    let time_mad = median(&mut times.iter().map(|v| (v - time_median).abs()).collect::<Vec<_>>());
+
+    let mut status_counts: HashMap<u16, usize> = HashMap::new();
+    for r in results {
+        *status_counts.entry(r.status).or_insert(0) += 1;
+    }
+    let dominant_404 = status_counts.get(&404).copied().unwrap_or(0) as f64 / results.len() as f64
+        >= DOMINANT_STATUS_RATIO;
+
+    let mut findings = Vec::new();
+    for (index, r) in results.iter().enumerate() {
+        let length_score = mad_score(r.content_length as f64, length_median, length_mad);
+        let time_score = mad_score(r.response_time_ms as f64, time_median, time_mad);
+        let outlier_score = length_score.max(time_score);
+
+        if r.reflected {
+            findings.push(Finding {
+                index,
+                level: Level::Critical,
+                score: 1000.0 + outlier_score,
+                reason: format!("reflected input in response body ({} {})", r.method, r.url),
+            });
+        } else if (500..600).contains(&r.status) {
+            findings.push(Finding {
+                index,
+                level: Level::Critical,
+                score: 900.0 + outlier_score,
+                reason: format!("{} server error", r.status),
+            });
+        } else if outlier_score >= OUTLIER_MAD_THRESHOLD {
+            findings.push(Finding {
+                index,
+                level: Level::Notable,
+                score: outlier_score,
+                reason: format!(
+                    "size/latency outlier (modified z-score {:.1} on content-length/response-time)",
+                    outlier_score
+                ),
+            });
+        } else if dominant_404 && matches!(r.status, 401 | 403) {
+            findings.push(Finding {
+                index,
+                level: Level::Notable,
+                score: 10.0,
+                reason: format!("{} on an otherwise 404-dominant target", r.status),
+            });
+        }
+    }
+
+    findings.sort_by(|a, b| b.level.cmp(&a.level).then(b.score.partial_cmp(&a.score).unwrap()));
+    findings
+}
+
+/// Stream every `FuzzResult` in a `.json`/`.jsonl`/`.csv` export through
+/// `on_row`, one record at a time, so `analyze_results` never has to hold
+/// the whole file in memory as a single `Vec`. `on_error` is called instead
+/// for rows that fail to deserialize (CSV/JSONL only — a malformed `.json`
+/// array fails to parse at all and is surfaced as an `Err` from this call).
+fn stream_results(
+    path: &str,
+    mut on_row: impl FnMut(FuzzResult),
+    mut on_error: impl FnMut(String),
+) -> Result<(), RustfuzzError> {
+    if path.ends_with(".jsonl") {
+        // Newline-delimited JSON (the same format the --resume journal
+        // writes): read and deserialize one line at a time so a multi-
+        // million-row file is never loaded into memory as a single array.
+        use std::io::BufRead;
+        let reader = std::io::BufReader::new(fs::File::open(path)?);
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str::<FuzzResult>(&line) {
+                Ok(r) => on_row(r),
+                Err(e) => on_error(e.to_string()),
+            }
+        }
+    } else if path.ends_with(".json") {
+        // A plain `--export results.json` is written as one JSON array, so
+        // it still has to be parsed whole; records are fed to `on_row` one
+        // at a time and the parsed array is dropped immediately after,
+        // rather than keeping it around alongside whatever analysis derives
+        // from it.
+        let file_content = fs::read_to_string(path)?;
+        let parsed: Vec<FuzzResult> = serde_json::from_str(&file_content)?;
+        drop(file_content);
+        for r in parsed {
+            on_row(r);
+        }
+    } else if path.ends_with(".csv") {
+        let mut rdr = csv::Reader::from_path(path)?;
+        for result in rdr.deserialize() {
+            match result {
+                Ok(r) => on_row(r),
+                Err(e) => on_error(e.to_string()),
+            }
+        }
+    } else {
+        return Err(RustfuzzError::UnsupportedFormat(path.to_string()));
+    }
+    Ok(())
+}
+
+/// Analyze the results from a JSON, JSONL or CSV export file and print them
+/// (console) or write them out as a Markdown/HTML report.
+async fn analyze_results(path: &str, format: OutputFormat, show_all: bool) -> Result<Summary, RustfuzzError> {
+    println!(":: Analyzing results from {path}");
+
+    // Pass 1: learn the exact (status, size, words, lines) distribution over
+    // the whole file, so soft-404 baselines are inferred from every row —
+    // not just whatever made it into the bounded sample below.
+    let mut total_rows = 0usize;
+    let mut signature_counts: HashMap<(u16, usize, usize, usize), usize> = HashMap::new();
+    stream_results(
+        path,
+        |r| {
+            total_rows += 1;
+            *signature_counts.entry((r.status, r.content_length, r.word_count, r.line_count)).or_insert(0) += 1;
+        },
+        |_| {},
+    )?;
+
+    if total_rows == 0 {
         println!("No results to analyze.");
-        return;
-    }
-
-    // Beautiful summary and table
-    let total = results.len();
-    let success = results.iter().filter(|r| r.status >= 200 && r.status < 300).count();
-    let redirects = results.iter().filter(|r| r.status >= 300 && r.status < 400).count();
-    let client_err = results.iter().filter(|r| r.status >= 400 && r.status < 500).count();
-    let server_err = results.iter().filter(|r| r.status >= 500 && r.status < 600).count();
-    let reflected = results.iter().filter(|r| r.reflected).count();
-    let errors = results.iter().filter(|r| r.error.is_some()).count();
-
-    println!("\n===== Analysis Summary =====");
-    println!("Total Results : {}", total);
-    println!("2xx Success   : {}", success);
-    println!("3xx Redirects : {}", redirects);
-    println!("4xx ClientErr : {}", client_err);
-    println!("5xx ServerErr : {}", server_err);
-    println!("Reflected     : {}", reflected);
-    println!("Errors        : {}", errors);
-    println!("===========================\n");
-
-    // Pretty table (first 20 results)
-    println!("{:<5} {:<45} {:<8} {:<10} {:<10}", "Code", "URL", "Word", "Reflected", "Error");
-    println!("{}", "-".repeat(90));
-    for r in results.iter().take(20) {
-        println!(
-            "{:<5} {:<45} {:<8} {:<10} {:<10}",
-            r.status,
-            truncate(&r.url, 45),
-            truncate(&r.word, 8),
-            if r.reflected { "yes" } else { "" },
-            r.error.as_ref().map(|e| truncate(e, 10)).unwrap_or("".to_string())
-        );
+        return Ok(Summary::default());
     }
-    if results.len() > 20 {
-        println!("... ({} more rows)", results.len() - 20);
+
+    let baselines = infer_baselines(total_rows, signature_counts);
+
+    // Pass 2: stream again, now tallying the exact summary counts (honoring
+    // --show-all against the baselines just inferred) and filling a bounded
+    // reservoir sample for severity scoring and the preview table/report.
+    let mut tally = StreamingTally::default();
+    let mut filtered = 0usize;
+    let mut row_errors = 0usize;
+    let mut sample: Reservoir<FuzzResult> = Reservoir::new(ANALYSIS_SAMPLE_SIZE);
+    stream_results(
+        path,
+        |r| {
+            let is_baseline = matches_baseline(&r, &baselines);
+            if is_baseline {
+                filtered += 1;
+            }
+            if show_all || !is_baseline {
+                tally.record(&r);
+                sample.offer(r);
+            }
+        },
+        |e| {
+            eprintln!("Failed to deserialize row: {e}");
+            row_errors += 1;
+        },
+    )?;
+    tally.row_errors = row_errors;
+
+    let sampled = sample.seen > sample.capacity;
+    let results = sample.sample;
+
+    let findings = score_findings(&results);
+    let mut summary = Summary::from_tally(&tally, &findings);
+    summary.filtered = filtered;
+    summary.baselines = baselines;
+
+    match format {
+        OutputFormat::Console => {
+            println!("\n===== Analysis Summary =====");
+            if sampled {
+                println!(
+                    "(dataset has {total_rows} rows, {} kept after baseline filtering; severity/preview below are drawn from a {}-row random sample of the kept rows)",
+                    summary.total,
+                    results.len()
+                );
+            }
+            println!("Total Results : {}", summary.total);
+            println!("2xx Success   : {}", summary.success);
+            println!("3xx Redirects : {}", summary.redirects);
+            println!("4xx ClientErr : {}", summary.client_err);
+            println!("5xx ServerErr : {}", summary.server_err);
+            println!("Reflected     : {}", summary.reflected);
+            println!("Errors        : {}", summary.errors);
+            if summary.row_errors > 0 {
+                println!("Row errors    : {}", summary.row_errors);
+            }
+            if !summary.baselines.is_empty() {
+                println!(
+                    "Filtered      : {} (soft-404 baseline{}; pass --show-all to include)",
+                    summary.filtered,
+                    if summary.baselines.len() > 1 { "s" } else { "" }
+                );
+                for b in &summary.baselines {
+                    println!(
+                        "  baseline: status={} size={} words={} lines={} ({} hits)",
+                        b.status, b.content_length, b.word_count, b.line_count, b.count
+                    );
+                }
+            }
+            println!("===========================\n");
+
+            if !findings.is_empty() {
+                println!("===== Top Findings =====");
+                for f in findings.iter().take(20) {
+                    let r = &results[f.index];
+                    println!(
+                        "[{:<8}] {:<6} {:<5} {:<40} {}",
+                        f.level.to_string(),
+                        r.method,
+                        r.status,
+                        truncate(&r.url, 40),
+                        f.reason
+                    );
+                }
+                if findings.len() > 20 {
+                    println!("... ({} more findings)", findings.len() - 20);
+                }
+                println!("=========================\n");
+            }
+
+            // Pretty table (first 20 results)
+            println!(
+                "{:<6} {:<5} {:<45} {:<8} {:<6} {:<6} {:<6} {:<10} {:<10}",
+                "Method", "Code", "URL", "Word", "Size", "Words", "Lines", "Reflected", "Error"
+            );
+            println!("{}", "-".repeat(118));
+            for r in results.iter().take(20) {
+                println!(
+                    "{:<6} {:<5} {:<45} {:<8} {:<6} {:<6} {:<6} {:<10} {:<10}",
+                    r.method,
+                    r.status,
+                    truncate(&r.url, 45),
+                    truncate(&r.word, 8),
+                    r.content_length,
+                    r.word_count,
+                    r.line_count,
+                    if r.reflected { "yes" } else { "" },
+                    r.error.as_ref().map(|e| truncate(e, 10)).unwrap_or("".to_string())
+                );
+            }
+            if results.len() > 20 {
+                println!("... ({} more rows)", results.len() - 20);
+            }
+        }
+        OutputFormat::Markdown => {
+            let sample_of = sampled.then_some(total_rows);
+            if sampled {
+                println!(":: Dataset has {total_rows} rows; report table covers a {}-row random sample (summary counts are exact)", results.len());
+            }
+            let report = render_markdown_report(&summary, &results, sample_of);
+            let out_path = report_output_path(path, format);
+            fs::write(&out_path, report)?;
+            println!(":: Markdown report written to {out_path}");
+        }
+        OutputFormat::Html => {
+            let sample_of = sampled.then_some(total_rows);
+            if sampled {
+                println!(":: Dataset has {total_rows} rows; report table covers a {}-row random sample (summary counts are exact)", results.len());
+            }
+            let report = render_html_report(&summary, &results, sample_of);
+            let out_path = report_output_path(path, format);
+            fs::write(&out_path, report)?;
+            println!(":: HTML report written to {out_path}");
+        }
     }
+
+    Ok(summary)
 }
 
 fn truncate(s: &str, max: usize) -> String {